@@ -0,0 +1,156 @@
+use rand::Rng;
+
+use crate::state::StateStore;
+
+use super::reaction::{select_by_propensity, ReactionNetwork};
+
+/// Exact stochastic simulation via Gillespie's direct method.
+///
+/// On each step every reaction's propensity is evaluated, the waiting time to the next event is
+/// drawn from `Exponential(a_0)`, and the firing reaction is chosen with probability
+/// proportional to its propensity. This is exact but, unlike [`super::TauLeapingSolver`], fires
+/// reactions one at a time, so it can be slow on high-copy-number networks.
+pub struct GillespieSolver;
+
+impl GillespieSolver {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Run the exact SSA from `t0` to `t_end`, recording a snapshot of `state[name]` at every
+    /// time in `output_times` (assumed sorted) into `store`'s trajectory log for `name`.
+    ///
+    /// Output snapshots use hold-last-value interpolation: each requested time gets whatever
+    /// state was current at that instant, without interpolating between reaction events.
+    #[allow(clippy::too_many_arguments)]
+    pub fn run(
+        &self,
+        network: &ReactionNetwork,
+        store: &mut StateStore,
+        name: &str,
+        t0: f64,
+        t_end: f64,
+        output_times: &[f64],
+        rng: &mut impl Rng,
+    ) {
+        let mut x = store.get(name).cloned().unwrap_or_default();
+        let mut t = t0;
+        let mut next_output = 0;
+
+        let record_up_to = |store: &mut StateStore, next_output: &mut usize, t: f64, x: &[f64]| {
+            while *next_output < output_times.len() && output_times[*next_output] <= t {
+                store.record_snapshot(name, output_times[*next_output], x.to_vec());
+                *next_output += 1;
+            }
+        };
+
+        loop {
+            let a = network.evaluate_propensities(&x);
+            let a0: f64 = a.iter().sum();
+            if a0 <= 0.0 {
+                break;
+            }
+
+            let dt = -rng.gen::<f64>().ln() / a0;
+            let next_t = t + dt;
+            if next_t > t_end {
+                break;
+            }
+
+            record_up_to(store, &mut next_output, next_t, &x);
+
+            let j = select_by_propensity(&a, a0, rng);
+            for (i, &v) in network.stoichiometry()[j].iter().enumerate() {
+                x[i] += v;
+            }
+            t = next_t;
+        }
+
+        // Any remaining requested times after the last event hold the final state.
+        while next_output < output_times.len() {
+            store.record_snapshot(name, output_times[next_output], x.clone());
+            next_output += 1;
+        }
+
+        store.set(name, x);
+    }
+}
+
+impl Default for GillespieSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::seeded_rng;
+
+    /// A -> 0 at propensity `rate * x[0]`.
+    fn decay_network(rate: f64) -> ReactionNetwork {
+        ReactionNetwork::new(
+            vec![Box::new(move |x: &[f64]| rate * x[0])],
+            vec![vec![-1.0]],
+        )
+    }
+
+    #[test]
+    fn test_run_decay_records_one_snapshot_per_output_time_and_reaches_zero() {
+        let network = decay_network(50.0);
+        let mut store = StateStore::new();
+        store.set("A", vec![1.0]);
+        let mut rng = seeded_rng(1);
+        let output_times = [0.01, 1.0, 5.0, 9.99];
+        GillespieSolver::new().run(&network, &mut store, "A", 0.0, 10.0, &output_times, &mut rng);
+
+        let trajectory = store.trajectory("A").unwrap();
+        assert_eq!(trajectory.len(), output_times.len());
+        for (snapshot, &expected_t) in trajectory.iter().zip(output_times.iter()) {
+            assert_eq!(snapshot.0, expected_t);
+        }
+        // The a0 <= 0 termination fires once the single molecule has decayed, and every
+        // subsequent requested time holds that final (zero) state.
+        assert_eq!(store.get("A"), Some(&vec![0.0]));
+        assert_eq!(trajectory.last().unwrap().1, vec![0.0]);
+    }
+
+    #[test]
+    fn test_run_birth_death_hovers_near_steady_state() {
+        // Birth at constant rate k1, death proportional to count at rate k2: steady-state mean
+        // is k1/k2.
+        let k1 = 20.0;
+        let k2 = 1.0;
+        let network = ReactionNetwork::new(
+            vec![
+                Box::new(move |_: &[f64]| k1),
+                Box::new(move |x: &[f64]| k2 * x[0]),
+            ],
+            vec![vec![1.0], vec![-1.0]],
+        );
+        let mut store = StateStore::new();
+        store.set("A", vec![k1 / k2]);
+        let mut rng = seeded_rng(2);
+        GillespieSolver::new().run(&network, &mut store, "A", 0.0, 200.0, &[], &mut rng);
+
+        let final_count = store.get("A").unwrap()[0];
+        assert!((final_count - k1 / k2).abs() < 5.0 * (k1 / k2).sqrt());
+    }
+
+    #[test]
+    fn test_run_stops_at_t_end_and_holds_state_when_nothing_fires() {
+        // Decay slow enough, and a window short enough, that no reaction fires before t_end: the
+        // next candidate event time always lands past t_end, so the loop must break on the
+        // `next_t > t_end` cutoff without touching the state.
+        let network = decay_network(1e-6);
+        let mut store = StateStore::new();
+        store.set("A", vec![1000.0]);
+        let mut rng = seeded_rng(3);
+        GillespieSolver::new().run(&network, &mut store, "A", 0.0, 0.001, &[0.0005], &mut rng);
+
+        let trajectory = store.trajectory("A").unwrap();
+        assert_eq!(trajectory.len(), 1);
+        assert_eq!(trajectory[0].1, vec![1000.0]);
+        assert_eq!(store.get("A"), Some(&vec![1000.0]));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod gillespie;
+pub mod reaction;
+pub mod tau_leaping;
+
+pub use gillespie::GillespieSolver;
+pub use reaction::ReactionNetwork;
+pub use tau_leaping::TauLeapingSolver;
@@ -0,0 +1,79 @@
+use rand::Rng;
+
+/// A single reaction's propensity function `a_j(x)`, given the current species counts `x`.
+pub type Propensity = Box<dyn Fn(&[f64]) -> f64>;
+
+/// A reaction network expressed as propensity functions paired with stoichiometry vectors.
+///
+/// Reaction `j` contributes a propensity `a_j(x)` given the current species counts `x`; when it
+/// fires it updates the state by its stoichiometry vector `v_j`. Both the exact and approximate
+/// SSA solvers operate on this shared representation.
+pub struct ReactionNetwork {
+    propensities: Vec<Propensity>,
+    stoichiometry: Vec<Vec<f64>>,
+}
+
+impl ReactionNetwork {
+    /// Build a network from parallel lists of propensity functions and stoichiometry vectors.
+    ///
+    /// # Panics
+    /// Panics if `propensities` and `stoichiometry` do not have the same length, or if the
+    /// stoichiometry vectors don't all name the same number of species.
+    pub fn new(propensities: Vec<Propensity>, stoichiometry: Vec<Vec<f64>>) -> Self {
+        assert_eq!(
+            propensities.len(),
+            stoichiometry.len(),
+            "one stoichiometry vector is required per reaction"
+        );
+        if let Some(expected) = stoichiometry.first().map(Vec::len) {
+            assert!(
+                stoichiometry.iter().all(|v| v.len() == expected),
+                "every stoichiometry vector must name the same number of species"
+            );
+        }
+        Self {
+            propensities,
+            stoichiometry,
+        }
+    }
+
+    /// Number of reactions in the network.
+    pub fn len(&self) -> usize {
+        self.propensities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.propensities.is_empty()
+    }
+
+    /// Number of species each stoichiometry vector covers, i.e. the length the state array
+    /// passed to the solvers is expected to have.
+    pub fn species_count(&self) -> usize {
+        self.stoichiometry.first().map_or(0, Vec::len)
+    }
+
+    /// Evaluate every reaction's propensity at state `x`.
+    pub fn evaluate_propensities(&self, x: &[f64]) -> Vec<f64> {
+        self.propensities.iter().map(|a| a(x)).collect()
+    }
+
+    /// Stoichiometry vector for each reaction, indexed the same way as `evaluate_propensities`.
+    pub fn stoichiometry(&self) -> &[Vec<f64>] {
+        &self.stoichiometry
+    }
+}
+
+/// Pick a reaction index with probability proportional to its propensity, i.e. the
+/// weighted-index sampling `WeightedIndex` provides, implemented directly over a cumulative sum
+/// of `a` so both the exact and tau-leaping solvers can share it.
+pub(crate) fn select_by_propensity(a: &[f64], a0: f64, rng: &mut impl Rng) -> usize {
+    let target = rng.gen::<f64>() * a0;
+    let mut cumulative = 0.0;
+    for (j, &aj) in a.iter().enumerate() {
+        cumulative += aj;
+        if target < cumulative {
+            return j;
+        }
+    }
+    a.len() - 1
+}
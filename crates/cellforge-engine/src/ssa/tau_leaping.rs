@@ -1,10 +1,236 @@
+use rand::Rng;
+
+use crate::state::StateStore;
+use crate::utils::rng::sample_poisson;
+
+use super::reaction::{select_by_propensity, ReactionNetwork};
+
+/// Default relative tolerance on per-species count change used by the leap-condition check.
+const DEFAULT_EPSILON: f64 = 0.03;
+
+/// Smallest leap we'll attempt before giving up and taking an exact SSA step instead.
+const MIN_TAU: f64 = 1e-12;
+
 /// Tau-leaping approximate SSA solver for faster stochastic simulation.
+///
+/// Instead of firing one reaction at a time like the exact Gillespie method, this leaps forward
+/// by an interval `tau` and fires every reaction a Poisson-distributed number of times, trading
+/// a small amount of accuracy for a large speedup on high-copy-number reaction networks.
 pub struct TauLeapingSolver {
-    _private: (),
+    epsilon: f64,
 }
 
 impl TauLeapingSolver {
     pub fn new() -> Self {
-        Self { _private: () }
+        Self {
+            epsilon: DEFAULT_EPSILON,
+        }
+    }
+
+    /// Use a custom leap-condition tolerance in place of [`DEFAULT_EPSILON`].
+    pub fn with_epsilon(epsilon: f64) -> Self {
+        Self { epsilon }
+    }
+
+    /// Advance the species array stored under `name` in `store` from `t0` to `t_end`.
+    ///
+    /// Draws are taken from `rng`, which callers obtain from `seeded_rng` so runs stay
+    /// reproducible. If a leap would drive a species negative, `tau` is halved and retried;
+    /// once `tau` collapses to near zero the state is instead advanced by a single exact SSA
+    /// step, whose reaction is chosen among all reactions in proportion to their propensities.
+    pub fn run(
+        &self,
+        network: &ReactionNetwork,
+        store: &mut StateStore,
+        name: &str,
+        t0: f64,
+        t_end: f64,
+        rng: &mut impl Rng,
+    ) {
+        let mut x = store.get(name).cloned().unwrap_or_default();
+        let mut t = t0;
+
+        while t < t_end {
+            let a = network.evaluate_propensities(&x);
+            let a0: f64 = a.iter().sum();
+            if a0 <= 0.0 {
+                break;
+            }
+
+            let mut tau = self.select_tau(network, &a, &x).min(t_end - t);
+            loop {
+                let firings = self.fire(&a, tau, rng);
+                if let Some(next) = apply_firings(network, &x, &firings) {
+                    x = next;
+                    break;
+                }
+                tau *= 0.5;
+                if tau < MIN_TAU {
+                    // Reactants are nearly exhausted: fall back to a single exact SSA step.
+                    let (dt, j) = exact_single_reaction(&a, a0, rng);
+                    tau = dt.min(t_end - t);
+                    x = apply_reaction(network, &x, j);
+                    break;
+                }
+            }
+            t += tau;
+        }
+
+        store.set(name, x);
+    }
+
+    /// Pick `tau` so that the expected change of each species count, and its standard deviation,
+    /// stay within `max(epsilon * x_i, 1)` over the leap (the species-based leap condition of
+    /// Cao, Gillespie & Petzold). Propensity changes are not bounded against `a_0` directly.
+    fn select_tau(&self, network: &ReactionNetwork, a: &[f64], x: &[f64]) -> f64 {
+        let n = x.len();
+        let mut mu = vec![0.0; n];
+        let mut sigma2 = vec![0.0; n];
+        for (j, &aj) in a.iter().enumerate() {
+            if aj <= 0.0 {
+                continue;
+            }
+            for ((mu_i, sigma2_i), &v) in mu
+                .iter_mut()
+                .zip(sigma2.iter_mut())
+                .zip(network.stoichiometry()[j].iter())
+            {
+                *mu_i += v * aj;
+                *sigma2_i += v * v * aj;
+            }
+        }
+
+        let mut tau = f64::INFINITY;
+        for i in 0..n {
+            let bound = (self.epsilon * x[i]).max(1.0);
+            if mu[i].abs() > 1e-12 {
+                tau = tau.min(bound / mu[i].abs());
+            }
+            if sigma2[i] > 1e-12 {
+                tau = tau.min(bound * bound / sigma2[i]);
+            }
+        }
+
+        if !tau.is_finite() || tau <= 0.0 {
+            tau = 1e-3;
+        }
+        tau
+    }
+
+    /// Sample a firing count `k_j ~ Poisson(a_j * tau)` for every reaction.
+    fn fire(&self, a: &[f64], tau: f64, rng: &mut impl Rng) -> Vec<u64> {
+        a.iter()
+            .map(|&aj| sample_poisson(rng, (aj * tau).max(0.0)))
+            .collect()
+    }
+}
+
+impl Default for TauLeapingSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Apply a vector of reaction firing counts to `x`, returning `None` if any species would go
+/// negative so the caller can shrink `tau` and retry.
+fn apply_firings(network: &ReactionNetwork, x: &[f64], firings: &[u64]) -> Option<Vec<f64>> {
+    let mut next = x.to_vec();
+    for (j, &k) in firings.iter().enumerate() {
+        if k == 0 {
+            continue;
+        }
+        for (next_i, &v) in next.iter_mut().zip(network.stoichiometry()[j].iter()) {
+            *next_i += v * k as f64;
+        }
+    }
+    if next.iter().any(|&count| count < 0.0) {
+        None
+    } else {
+        Some(next)
+    }
+}
+
+fn apply_reaction(network: &ReactionNetwork, x: &[f64], j: usize) -> Vec<f64> {
+    let mut next = x.to_vec();
+    for (next_i, &v) in next.iter_mut().zip(network.stoichiometry()[j].iter()) {
+        *next_i += v;
+    }
+    next
+}
+
+/// Take a single exact Gillespie step given precomputed propensities, returning the waiting
+/// time and the index of the reaction that fired.
+fn exact_single_reaction(a: &[f64], a0: f64, rng: &mut impl Rng) -> (f64, usize) {
+    let dt = -rng.gen::<f64>().ln() / a0;
+    (dt, select_by_propensity(a, a0, rng))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::seeded_rng;
+
+    /// A -> 0 at propensity `rate * x[0]`.
+    fn decay_network(rate: f64) -> ReactionNetwork {
+        ReactionNetwork::new(
+            vec![Box::new(move |x: &[f64]| rate * x[0])],
+            vec![vec![-1.0]],
+        )
+    }
+
+    #[test]
+    fn test_run_decay_stays_nonnegative_and_decreases() {
+        let network = decay_network(0.5);
+        let mut store = StateStore::new();
+        store.set("A", vec![1000.0]);
+        let mut rng = seeded_rng(1);
+        TauLeapingSolver::new().run(&network, &mut store, "A", 0.0, 5.0, &mut rng);
+
+        let final_count = store.get("A").unwrap()[0];
+        assert!(final_count >= 0.0);
+        assert!(final_count < 1000.0);
+    }
+
+    #[test]
+    fn test_run_low_copy_number_never_goes_negative() {
+        // A couple of molecules decaying fast enough that a naive leap would overshoot and drive
+        // the count negative, forcing the tau-halving guard to shrink `tau` repeatedly until it
+        // either succeeds or collapses below `MIN_TAU` and falls back to an exact SSA step.
+        let network = decay_network(50.0);
+        let mut store = StateStore::new();
+        store.set("A", vec![2.0]);
+        let mut rng = seeded_rng(3);
+        TauLeapingSolver::new().run(&network, &mut store, "A", 0.0, 2.0, &mut rng);
+
+        let final_count = store.get("A").unwrap()[0];
+        assert!((0.0..=2.0).contains(&final_count));
+    }
+
+    #[test]
+    fn test_select_tau_shrinks_as_epsilon_tightens() {
+        let network = decay_network(1.0);
+        let x = vec![100.0];
+        let a = network.evaluate_propensities(&x);
+
+        let loose = TauLeapingSolver::with_epsilon(0.5);
+        let tight = TauLeapingSolver::with_epsilon(0.01);
+        assert!(tight.select_tau(&network, &a, &x) < loose.select_tau(&network, &a, &x));
+    }
+
+    #[test]
+    fn test_apply_firings_rejects_counts_that_would_go_negative() {
+        let network = decay_network(1.0);
+        // A single molecule can absorb one firing of the decay reaction but not two.
+        assert_eq!(apply_firings(&network, &[1.0], &[1]), Some(vec![0.0]));
+        assert!(apply_firings(&network, &[1.0], &[2]).is_none());
+    }
+
+    #[test]
+    fn test_exact_single_reaction_selects_the_only_reaction() {
+        let mut rng = seeded_rng(4);
+        let a = vec![5.0];
+        let (dt, j) = exact_single_reaction(&a, 5.0, &mut rng);
+        assert_eq!(j, 0);
+        assert!(dt > 0.0);
     }
 }
@@ -3,12 +3,43 @@ use std::collections::HashMap;
 /// Central state store holding all simulation variables.
 pub struct StateStore {
     arrays: HashMap<String, Vec<f64>>,
+    trajectories: HashMap<String, Vec<(f64, Vec<f64>)>>,
 }
 
 impl StateStore {
     pub fn new() -> Self {
         Self {
             arrays: HashMap::new(),
+            trajectories: HashMap::new(),
         }
     }
+
+    /// Look up a named array.
+    pub fn get(&self, name: &str) -> Option<&Vec<f64>> {
+        self.arrays.get(name)
+    }
+
+    /// Insert or replace a named array.
+    pub fn set(&mut self, name: &str, values: Vec<f64>) {
+        self.arrays.insert(name.to_string(), values);
+    }
+
+    /// Append a `(time, values)` snapshot to a named trajectory, e.g. an SSA output time series.
+    pub fn record_snapshot(&mut self, name: &str, time: f64, values: Vec<f64>) {
+        self.trajectories
+            .entry(name.to_string())
+            .or_default()
+            .push((time, values));
+    }
+
+    /// The recorded snapshots for a named trajectory, in the order they were pushed.
+    pub fn trajectory(&self, name: &str) -> Option<&[(f64, Vec<f64>)]> {
+        self.trajectories.get(name).map(Vec::as_slice)
+    }
+}
+
+impl Default for StateStore {
+    fn default() -> Self {
+        Self::new()
+    }
 }
@@ -0,0 +1,3 @@
+pub mod reseeding;
+pub mod rng;
+pub mod sampling;
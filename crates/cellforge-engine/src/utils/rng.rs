@@ -1,22 +1,133 @@
+#[cfg(feature = "small-rng")]
+use rand::rngs::SmallRng;
 use rand::rngs::StdRng;
-use rand::SeedableRng;
+use rand::{Rng, SeedableRng};
 
 /// Create a seeded random number generator for reproducible simulations.
+///
+/// This always returns the ChaCha20-backed `StdRng`. It's slower than a non-cryptographic PRNG,
+/// but its output stream is stable across `rand` versions, so archived results stay reproducible
+/// even after the crate is upgraded. For ensemble runs that draw billions of numbers and only
+/// need reproducibility within a single run, see [`seeded_small_rng`].
 pub fn seeded_rng(seed: u64) -> StdRng {
     StdRng::seed_from_u64(seed)
 }
 
+/// Create a seeded lightweight (xoshiro/PCG-class) random number generator.
+///
+/// Much faster than [`seeded_rng`] for simulations that draw billions of random numbers, at the
+/// cost of cross-version reproducibility: `SmallRng`'s algorithm isn't guaranteed to stay the
+/// same between `rand` releases, so don't rely on it the way you can rely on `StdRng`'s ChaCha
+/// stream for long-term archival reproducibility. Gated behind the `small-rng` feature since most
+/// callers should default to `seeded_rng`.
+///
+/// Both the exact and tau-leaping solvers take their generator as `&mut impl Rng` rather than a
+/// concrete type, so passing the output of `seeded_rng` or `seeded_small_rng` in is how a caller
+/// already picks the generator for an entire SSA/tau-leaping run. There's no ODE solver in this
+/// crate yet to thread the same choice through; that's left for whenever one lands.
+#[cfg(feature = "small-rng")]
+pub fn seeded_small_rng(seed: u64) -> SmallRng {
+    SmallRng::seed_from_u64(seed)
+}
+
+/// Draw a Poisson(`lambda`) count.
+///
+/// For small `lambda` this multiplies uniform draws together until the running product drops
+/// below `e^-lambda` (Knuth's method). For large `lambda` that loop would take too long, so we
+/// switch to Atkinson's rejection sampling against a logistic envelope (not a Lorentzian/Cauchy
+/// one), which stays fast however large the mean gets.
+pub(crate) fn sample_poisson(rng: &mut impl Rng, lambda: f64) -> u64 {
+    if lambda <= 0.0 {
+        return 0;
+    }
+    if lambda < 30.0 {
+        let limit = (-lambda).exp();
+        let mut product = 1.0;
+        let mut k = 0u64;
+        loop {
+            product *= rng.gen::<f64>();
+            if product <= limit {
+                return k;
+            }
+            k += 1;
+        }
+    }
+
+    let c = 0.767 - 3.36 / lambda;
+    let beta = std::f64::consts::PI / (3.0 * lambda).sqrt();
+    let alpha = beta * lambda;
+    let k_base = c.ln() - beta.ln() - lambda;
+    loop {
+        let u = rng.gen::<f64>();
+        let x = (alpha - ((1.0 - u) / u).ln()) / beta;
+        let n = x.round();
+        if n < 0.0 {
+            continue;
+        }
+        let v = rng.gen::<f64>();
+        let y = alpha - beta * x;
+        let exp_y = y.exp();
+        let lhs = y + (v / ((1.0 + exp_y) * (1.0 + exp_y))).ln();
+        let rhs = k_base + n * lambda.ln() - ln_factorial(n as u64);
+        if lhs <= rhs {
+            return n as u64;
+        }
+    }
+}
+
+/// Stirling's approximation to `ln(n!)`, accurate enough to drive the Poisson rejection test.
+fn ln_factorial(n: u64) -> f64 {
+    if n < 2 {
+        return 0.0;
+    }
+    let x = n as f64;
+    x * x.ln() - x + 0.5 * (2.0 * std::f64::consts::PI * x).ln() + 1.0 / (12.0 * x)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_seeded_rng_deterministic() {
-        use rand::Rng;
         let mut rng1 = seeded_rng(42);
         let mut rng2 = seeded_rng(42);
         let v1: f64 = rng1.gen();
         let v2: f64 = rng2.gen();
         assert_eq!(v1, v2);
     }
+
+    #[test]
+    fn test_sample_poisson_mean_and_variance_track_lambda() {
+        for &lambda in &[2.0, 15.0, 200.0] {
+            let mut rng = seeded_rng(7);
+            let n = 20_000;
+            let samples: Vec<f64> = (0..n)
+                .map(|_| sample_poisson(&mut rng, lambda) as f64)
+                .collect();
+            let mean = samples.iter().sum::<f64>() / n as f64;
+            let variance =
+                samples.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n as f64 - 1.0);
+            assert!(
+                (mean - lambda).abs() < 0.1 * lambda + 1.0,
+                "mean {mean} too far from lambda {lambda}"
+            );
+            // A Poisson(lambda) has variance == lambda; this is what catches a skewed envelope
+            // that still happens to land on roughly the right mean.
+            assert!(
+                (variance - lambda).abs() < 0.15 * lambda + 1.0,
+                "variance {variance} too far from lambda {lambda}"
+            );
+        }
+    }
+
+    #[cfg(feature = "small-rng")]
+    #[test]
+    fn test_seeded_small_rng_deterministic() {
+        let mut rng1 = seeded_small_rng(42);
+        let mut rng2 = seeded_small_rng(42);
+        let v1: f64 = rng1.gen();
+        let v2: f64 = rng2.gen();
+        assert_eq!(v1, v2);
+    }
 }
@@ -0,0 +1,161 @@
+use rand::Rng;
+
+use crate::state::StateStore;
+
+use super::rng::sample_poisson;
+
+/// A named probability distribution that initial conditions and rate parameters can be drawn
+/// from, so an ensemble of cells can vary from run to run while staying reproducible from one
+/// master seed passed through `seeded_rng`.
+pub enum Distribution {
+    Uniform { low: f64, high: f64 },
+    Normal { mean: f64, std_dev: f64 },
+    LogNormal { mean: f64, std_dev: f64 },
+    Exponential { rate: f64 },
+    Gamma { shape: f64, scale: f64 },
+    Poisson { lambda: f64 },
+}
+
+impl Distribution {
+    /// Draw a single sample from this distribution.
+    pub fn sample(&self, rng: &mut impl Rng) -> f64 {
+        match *self {
+            Distribution::Uniform { low, high } => low + rng.gen::<f64>() * (high - low),
+            Distribution::Normal { mean, std_dev } => mean + std_dev * sample_standard_normal(rng),
+            Distribution::LogNormal { mean, std_dev } => {
+                (mean + std_dev * sample_standard_normal(rng)).exp()
+            }
+            Distribution::Exponential { rate } => {
+                -rng.gen::<f64>().max(f64::MIN_POSITIVE).ln() / rate
+            }
+            Distribution::Gamma { shape, scale } => scale * sample_standard_gamma(rng, shape),
+            Distribution::Poisson { lambda } => sample_poisson(rng, lambda) as f64,
+        }
+    }
+
+    /// Draw `n` independent samples.
+    pub fn sample_n(&self, rng: &mut impl Rng, n: usize) -> Vec<f64> {
+        (0..n).map(|_| self.sample(rng)).collect()
+    }
+}
+
+/// Initialize a named array in `store` by independently drawing `n` values from `distribution`,
+/// e.g. a per-cell initial species count or rate parameter for a Monte Carlo ensemble.
+pub fn fill_state_store(
+    store: &mut StateStore,
+    name: &str,
+    distribution: &Distribution,
+    n: usize,
+    rng: &mut impl Rng,
+) {
+    store.set(name, distribution.sample_n(rng, n));
+}
+
+/// Split `total` across `alpha.len()` species using Dirichlet-distributed proportions with
+/// concentration `alpha`, writing the resulting per-species counts to `name` in `store`.
+pub fn fill_state_store_dirichlet(
+    store: &mut StateStore,
+    name: &str,
+    alpha: &[f64],
+    total: f64,
+    rng: &mut impl Rng,
+) {
+    let counts = sample_dirichlet(rng, alpha)
+        .into_iter()
+        .map(|p| p * total)
+        .collect();
+    store.set(name, counts);
+}
+
+/// Draw a Dirichlet-distributed composition over `alpha.len()` categories with concentration
+/// parameters `alpha`; the draws sum to one.
+pub fn sample_dirichlet(rng: &mut impl Rng, alpha: &[f64]) -> Vec<f64> {
+    let draws: Vec<f64> = alpha
+        .iter()
+        .map(|&a| sample_standard_gamma(rng, a))
+        .collect();
+    let total: f64 = draws.iter().sum();
+    draws.into_iter().map(|g| g / total).collect()
+}
+
+/// Standard normal draw via the Box-Muller transform.
+fn sample_standard_normal(rng: &mut impl Rng) -> f64 {
+    let u1: f64 = rng.gen::<f64>().max(f64::MIN_POSITIVE);
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Standard Gamma(`shape`, 1) draw via the Marsaglia-Tsang method.
+///
+/// For `shape >= 1`: draw `d = shape - 1/3`, `c = 1/sqrt(9d)`, repeatedly sample a standard
+/// normal `z` and accept `x = d * (1 + c*z)^3` once `ln(u) < 0.5*z^2 + d - d*v + d*ln(v)`. For
+/// `shape < 1`, draw at `shape + 1` and scale by `u^(1/shape)`.
+fn sample_standard_gamma(rng: &mut impl Rng, shape: f64) -> f64 {
+    if shape < 1.0 {
+        let u: f64 = rng.gen();
+        return sample_standard_gamma(rng, shape + 1.0) * u.powf(1.0 / shape);
+    }
+
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+    loop {
+        let z = sample_standard_normal(rng);
+        if z <= -1.0 / c {
+            continue;
+        }
+        let v = (1.0 + c * z).powi(3);
+        let u: f64 = rng.gen();
+        if u.ln() < 0.5 * z * z + d - d * v + d * v.ln() {
+            return d * v;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::seeded_rng;
+
+    #[test]
+    fn test_uniform_sample_in_bounds() {
+        let mut rng = seeded_rng(1);
+        let dist = Distribution::Uniform {
+            low: 2.0,
+            high: 5.0,
+        };
+        for v in dist.sample_n(&mut rng, 1000) {
+            assert!((2.0..5.0).contains(&v));
+        }
+    }
+
+    #[test]
+    fn test_gamma_mean_tracks_shape_and_scale() {
+        let mut rng = seeded_rng(2);
+        let dist = Distribution::Gamma {
+            shape: 3.0,
+            scale: 2.0,
+        };
+        let samples = dist.sample_n(&mut rng, 20_000);
+        let mean: f64 = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!((mean - 6.0).abs() < 0.3, "mean {mean} too far from 6.0");
+    }
+
+    #[test]
+    fn test_dirichlet_sums_to_one() {
+        let mut rng = seeded_rng(3);
+        let proportions = sample_dirichlet(&mut rng, &[1.0, 2.0, 3.0]);
+        let total: f64 = proportions.iter().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_exponential_sample_is_always_finite_and_positive() {
+        // `gen::<f64>()` can return exactly 0.0, which would make the un-clamped `-u.ln() / rate`
+        // diverge to +inf; every draw must stay finite.
+        let mut rng = seeded_rng(4);
+        let dist = Distribution::Exponential { rate: 2.0 };
+        for v in dist.sample_n(&mut rng, 20_000) {
+            assert!(v.is_finite() && v >= 0.0, "non-finite or negative draw: {v}");
+        }
+    }
+}